@@ -2,9 +2,17 @@ use std::{
     borrow::Borrow,
     collections::{HashSet, TryReserveError},
     hash::{BuildHasher, Hash, RandomState},
+    iter::FusedIterator,
     marker::PhantomData,
+    ops::{BitAnd, BitOr, BitXor, Deref, DerefMut, Sub},
 };
 
+mod linked_set;
+pub use linked_set::PartialLinkedSet;
+
+#[cfg(test)]
+mod test;
+
 pub trait ToPartial<P> {
     fn to_partial(&self) -> &P;
 }
@@ -33,6 +41,10 @@ where
     pub fn into_value(self) -> V {
         self.value
     }
+
+    pub(crate) fn value(&self) -> &V {
+        &self.value
+    }
 }
 
 impl<V, P> Hash for Partial<V, P>
@@ -94,6 +106,19 @@ where
     }
 }
 
+impl<V, P> Clone for Partial<V, P>
+where
+    V: Clone + ToPartial<P>,
+    P: Hash + Eq,
+{
+    fn clone(&self) -> Self {
+        Partial {
+            value: self.value.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
 pub struct PartialSet<V, P, S = RandomState>
 where
     V: ToPartial<P>,
@@ -165,6 +190,116 @@ where
     }
 }
 
+pub struct Union<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: 'a,
+{
+    inner: std::collections::hash_set::Union<'a, Partial<V, P>, S>,
+}
+
+impl<'a, V, P, S> Iterator for Union<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: 'a + BuildHasher,
+{
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|v| &v.value)
+    }
+}
+
+pub struct Intersection<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: 'a,
+{
+    inner: std::collections::hash_set::Intersection<'a, Partial<V, P>, S>,
+}
+
+impl<'a, V, P, S> Iterator for Intersection<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: 'a + BuildHasher,
+{
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|v| &v.value)
+    }
+}
+
+pub struct SymmetricDifference<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: 'a,
+{
+    inner: std::collections::hash_set::SymmetricDifference<'a, Partial<V, P>, S>,
+}
+
+impl<'a, V, P, S> Iterator for SymmetricDifference<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: 'a + BuildHasher,
+{
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|v| &v.value)
+    }
+}
+
+pub struct ExtractIf<'a, V, P, S, F>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+    F: FnMut(&V) -> bool,
+{
+    set: &'a mut PartialSet<V, P, S>,
+    keys: std::vec::IntoIter<P>,
+    pred: F,
+}
+
+impl<'a, V, P, S, F> Iterator for ExtractIf<'a, V, P, S, F>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+    F: FnMut(&V) -> bool,
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for key in self.keys.by_ref() {
+            // the keys were snapshotted from a set with unique partial keys,
+            // each is visited once and the set is mutably borrowed by this
+            // iterator, so every key is still present when it is visited.
+            let matches = self.set.get(&key).is_some_and(|value| (self.pred)(value));
+            if matches {
+                return self.set.take(&key);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, V, P, S, F> FusedIterator for ExtractIf<'a, V, P, S, F>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+    F: FnMut(&V) -> bool,
+{
+}
+
 pub struct IntoIter<V, P>
 where
     V: Hash + Eq + ToPartial<P>,
@@ -185,6 +320,127 @@ where
     }
 }
 
+pub enum Entry<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    Occupied(OccupiedEntry<'a, V, P, S>),
+    Vacant(VacantEntry<'a, V, P, S>),
+}
+
+pub struct OccupiedEntry<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    value: &'a V,
+    _marker: PhantomData<(P, S)>,
+}
+
+impl<'a, V, P, S> OccupiedEntry<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    pub fn get(&self) -> &V {
+        self.value
+    }
+
+    pub fn into_value(self) -> &'a V {
+        self.value
+    }
+}
+
+pub struct VacantEntry<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    set: &'a mut PartialSet<V, P, S>,
+    key: P,
+}
+
+impl<'a, V, P, S> VacantEntry<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    pub fn insert(self, value: V) -> &'a V {
+        assert!(
+            value.to_partial() == &self.key,
+            "value's partial key does not match the key this entry was requested with"
+        );
+        self.set.inner.insert(Partial::from(value));
+        self.set.inner.get(&self.key).map(|p| &p.value).unwrap()
+    }
+}
+
+pub struct RefMut<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    set: &'a mut PartialSet<V, P, S>,
+    original_key: P,
+    slot: Option<V>,
+}
+
+impl<'a, V, P, S> Deref for RefMut<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        self.slot.as_ref().unwrap()
+    }
+}
+
+impl<'a, V, P, S> DerefMut for RefMut<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    fn deref_mut(&mut self) -> &mut V {
+        self.slot.as_mut().unwrap()
+    }
+}
+
+impl<'a, V, P, S> Drop for RefMut<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    fn drop(&mut self) {
+        let Some(value) = self.slot.take() else {
+            return;
+        };
+        // Only assert when we're not already unwinding: panicking again here
+        // while a panic is in flight would abort the process instead of
+        // propagating the original panic.
+        if std::thread::panicking() {
+            let _ = self.set.insert(value);
+            return;
+        }
+        assert!(
+            value.to_partial() == &self.original_key,
+            "logic error: value's partial key was modified while held by `get_mut`"
+        );
+        self.set.insert(value);
+    }
+}
+
 impl<V, P> PartialSet<V, P>
 where
     V: ToPartial<P>,
@@ -239,16 +495,68 @@ where
         }
     }
 
-    pub fn drain(&mut self) -> Drain<V, P> {
+    pub fn drain(&mut self) -> Drain<'_, V, P> {
         Drain {
             inner: self.inner.drain(),
         }
     }
 
+    pub fn entry(&mut self, key: P) -> Entry<'_, V, P, S> {
+        if self.inner.contains(&key) {
+            let value = self.inner.get(&key).unwrap().value();
+            Entry::Occupied(OccupiedEntry {
+                value,
+                _marker: PhantomData,
+            })
+        } else {
+            Entry::Vacant(VacantEntry { set: self, key })
+        }
+    }
+
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, V, P, S, F>
+    where
+        P: Clone,
+        F: FnMut(&V) -> bool,
+    {
+        let keys: Vec<P> = self.inner.iter().map(|p| p.value().to_partial().clone()).collect();
+        ExtractIf {
+            set: self,
+            keys: keys.into_iter(),
+            pred,
+        }
+    }
+
     pub fn get(&self, partial: &P) -> Option<&V> {
         self.inner.get(partial).map(|v| &v.value)
     }
 
+    pub fn get_mut(&mut self, key: &P) -> Option<RefMut<'_, V, P, S>>
+    where
+        P: Clone,
+    {
+        let value = self.take(key)?;
+        Some(RefMut {
+            set: self,
+            original_key: key.clone(),
+            slot: Some(value),
+        })
+    }
+
+    pub fn get_or_insert(&mut self, value: V) -> &V
+    where
+        P: Clone,
+    {
+        let key = value.to_partial().clone();
+        self.get_or_insert_with(&key, |_| value)
+    }
+
+    pub fn get_or_insert_with<F: FnOnce(&P) -> V>(&mut self, key: &P, f: F) -> &V {
+        if !self.inner.contains(key) {
+            self.inner.insert(Partial::from(f(key)));
+        }
+        self.inner.get(key).map(|p| &p.value).unwrap()
+    }
+
     pub fn hasher(&self) -> &S {
         self.inner.hasher()
     }
@@ -257,15 +565,29 @@ where
         self.inner.insert(Partial::from(value))
     }
 
-    // ...
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, V, P, S> {
+        Intersection {
+            inner: self.inner.intersection(&other.inner),
+        }
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.inner.is_disjoint(&other.inner)
+    }
 
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
 
-    //...
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.inner.is_subset(&other.inner)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        self.inner.is_superset(&other.inner)
+    }
 
-    pub fn iter(&self) -> Iter<V, P> {
+    pub fn iter(&self) -> Iter<'_, V, P> {
         Iter {
             inner: self.inner.iter(),
         }
@@ -302,7 +624,11 @@ where
         self.inner.shrink_to_fit();
     }
 
-    // ...
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, V, P, S> {
+        SymmetricDifference {
+            inner: self.inner.symmetric_difference(&other.inner),
+        }
+    }
 
     pub fn take<Q>(&mut self, value: &Q) -> Option<V>
     where
@@ -316,7 +642,24 @@ where
         self.inner.try_reserve(additional)
     }
 
-    // ...
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, V, P, S> {
+        Union {
+            inner: self.inner.union(&other.inner),
+        }
+    }
+
+    pub fn update_with<F: FnOnce(&mut V)>(&mut self, key: &P, f: F) -> bool
+    where
+        P: Clone,
+    {
+        match self.get_mut(key) {
+            Some(mut value) => {
+                f(&mut value);
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl<V, P, S> IntoIterator for PartialSet<V, P, S>
@@ -334,3 +677,122 @@ where
         }
     }
 }
+
+impl<V, P, S> Default for PartialSet<V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<V, P, S> Clone for PartialSet<V, P, S>
+where
+    V: Clone + ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<V, P, S> Extend<V> for PartialSet<V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = V>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<V, P, S> FromIterator<V> for PartialSet<V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut set = Self::with_hasher(S::default());
+        set.extend(iter);
+        set
+    }
+}
+
+impl<V, P, S> BitOr<&PartialSet<V, P, S>> for &PartialSet<V, P, S>
+where
+    V: ToPartial<P> + Clone,
+    P: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Output = PartialSet<V, P, S>;
+
+    fn bitor(self, rhs: &PartialSet<V, P, S>) -> PartialSet<V, P, S> {
+        let mut result = PartialSet::with_capacity_and_hasher(self.len().max(rhs.len()), S::default());
+        for value in self.union(rhs) {
+            result.insert(value.clone());
+        }
+        result
+    }
+}
+
+impl<V, P, S> BitAnd<&PartialSet<V, P, S>> for &PartialSet<V, P, S>
+where
+    V: ToPartial<P> + Clone,
+    P: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Output = PartialSet<V, P, S>;
+
+    fn bitand(self, rhs: &PartialSet<V, P, S>) -> PartialSet<V, P, S> {
+        let mut result = PartialSet::with_capacity_and_hasher(self.len().min(rhs.len()), S::default());
+        for value in self.intersection(rhs) {
+            result.insert(value.clone());
+        }
+        result
+    }
+}
+
+impl<V, P, S> BitXor<&PartialSet<V, P, S>> for &PartialSet<V, P, S>
+where
+    V: ToPartial<P> + Clone,
+    P: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Output = PartialSet<V, P, S>;
+
+    fn bitxor(self, rhs: &PartialSet<V, P, S>) -> PartialSet<V, P, S> {
+        let mut result =
+            PartialSet::with_capacity_and_hasher(self.len().saturating_add(rhs.len()), S::default());
+        for value in self.symmetric_difference(rhs) {
+            result.insert(value.clone());
+        }
+        result
+    }
+}
+
+impl<V, P, S> Sub<&PartialSet<V, P, S>> for &PartialSet<V, P, S>
+where
+    V: ToPartial<P> + Clone,
+    P: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Output = PartialSet<V, P, S>;
+
+    fn sub(self, rhs: &PartialSet<V, P, S>) -> PartialSet<V, P, S> {
+        let mut result = PartialSet::with_capacity_and_hasher(self.len(), S::default());
+        for value in self.difference(rhs) {
+            result.insert(value.clone());
+        }
+        result
+    }
+}
@@ -0,0 +1,323 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash, RandomState},
+};
+
+use crate::{Partial, ToPartial};
+
+struct Link<P> {
+    prev: Option<P>,
+    next: Option<P>,
+}
+
+pub struct PartialLinkedSet<V, P, S = RandomState>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    inner: HashMap<Partial<V, P>, Link<P>, S>,
+    front: Option<P>,
+    back: Option<P>,
+}
+
+pub struct Iter<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    inner: &'a HashMap<Partial<V, P>, Link<P>, S>,
+    current: Option<P>,
+}
+
+impl<'a, V, P, S> Iterator for Iter<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.current.take()?;
+        let (partial, link) = self.inner.get_key_value(&key)?;
+        self.current = link.next.clone();
+        Some(partial.value())
+    }
+}
+
+pub struct Drain<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    inner: &'a mut PartialLinkedSet<V, P, S>,
+}
+
+impl<'a, V, P, S> Iterator for Drain<'a, V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.pop_front()
+    }
+}
+
+pub struct IntoIter<V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    inner: PartialLinkedSet<V, P, S>,
+}
+
+impl<V, P, S> Iterator for IntoIter<V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.pop_front()
+    }
+}
+
+impl<V, P> PartialLinkedSet<V, P>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            inner: HashMap::new(),
+            front: None,
+            back: None,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: HashMap::with_capacity(capacity),
+            front: None,
+            back: None,
+        }
+    }
+}
+
+impl<V, P, S> PartialLinkedSet<V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            inner: HashMap::with_hasher(hasher),
+            front: None,
+            back: None,
+        }
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        Self {
+            inner: HashMap::with_capacity_and_hasher(capacity, hasher),
+            front: None,
+            back: None,
+        }
+    }
+
+    pub fn back(&self) -> Option<&V> {
+        let key = self.back.as_ref()?;
+        self.get(key)
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.front = None;
+        self.back = None;
+    }
+
+    pub fn contains(&self, partial: &P) -> bool {
+        self.inner.contains_key(partial)
+    }
+
+    pub fn drain(&mut self) -> Drain<'_, V, P, S> {
+        Drain { inner: self }
+    }
+
+    pub fn front(&self) -> Option<&V> {
+        let key = self.front.as_ref()?;
+        self.get(key)
+    }
+
+    pub fn get(&self, partial: &P) -> Option<&V> {
+        self.inner.get_key_value(partial).map(|(p, _)| p.value())
+    }
+
+    pub fn insert(&mut self, value: V) -> bool {
+        let key = value.to_partial().clone();
+        if self.inner.contains_key(&key) {
+            return false;
+        }
+
+        let prev = self.back.clone();
+        self.inner.insert(
+            Partial::from(value),
+            Link {
+                prev: prev.clone(),
+                next: None,
+            },
+        );
+
+        match &prev {
+            Some(prev_key) => self.inner.get_mut(prev_key).unwrap().next = Some(key.clone()),
+            None => self.front = Some(key.clone()),
+        }
+        self.back = Some(key);
+        true
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, V, P, S> {
+        Iter {
+            inner: &self.inner,
+            current: self.front.clone(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn pop_back(&mut self) -> Option<V> {
+        let key = self.back.clone()?;
+        self.take(&key)
+    }
+
+    pub fn pop_front(&mut self) -> Option<V> {
+        let key = self.front.clone()?;
+        self.take(&key)
+    }
+
+    pub fn remove(&mut self, partial: &P) -> bool {
+        self.take(partial).is_some()
+    }
+
+    pub fn to_back(&mut self, partial: &P) -> bool {
+        let Some(value) = self.take(partial) else {
+            return false;
+        };
+        self.insert(value)
+    }
+
+    pub fn to_front(&mut self, partial: &P) -> bool {
+        let Some(value) = self.take(partial) else {
+            return false;
+        };
+
+        let key = value.to_partial().clone();
+        let old_front = self.front.clone();
+        self.inner.insert(
+            Partial::from(value),
+            Link {
+                prev: None,
+                next: old_front.clone(),
+            },
+        );
+        if let Some(front_key) = &old_front {
+            self.inner.get_mut(front_key).unwrap().prev = Some(key.clone());
+        } else {
+            self.back = Some(key.clone());
+        }
+        self.front = Some(key);
+        true
+    }
+
+    fn take(&mut self, partial: &P) -> Option<V> {
+        let (partial_key, link) = self.inner.remove_entry(partial)?;
+
+        match &link.prev {
+            Some(prev_key) => self.inner.get_mut(prev_key).unwrap().next = link.next.clone(),
+            None => self.front = link.next.clone(),
+        }
+        match &link.next {
+            Some(next_key) => self.inner.get_mut(next_key).unwrap().prev = link.prev.clone(),
+            None => self.back = link.prev.clone(),
+        }
+
+        Some(partial_key.into_value())
+    }
+}
+
+impl<V, P, S> IntoIterator for PartialLinkedSet<V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    type Item = V;
+    type IntoIter = IntoIter<V, P, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { inner: self }
+    }
+}
+
+impl<V, P, S> Default for PartialLinkedSet<V, P, S>
+where
+    V: ToPartial<P>,
+    P: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        key: i32,
+        value: i32,
+    }
+
+    impl ToPartial<i32> for Test {
+        fn to_partial(&self) -> &i32 {
+            &self.key
+        }
+    }
+
+    #[test]
+    fn test_insertion_order() {
+        let mut set = PartialLinkedSet::new();
+        set.insert(Test { key: 1, value: 1 });
+        set.insert(Test { key: 2, value: 2 });
+        set.insert(Test { key: 3, value: 3 });
+
+        let keys: Vec<i32> = set.iter().map(|v| v.key).collect();
+        assert_eq!(keys, vec![1, 2, 3]);
+
+        set.to_front(&3);
+        let keys: Vec<i32> = set.iter().map(|v| v.key).collect();
+        assert_eq!(keys, vec![3, 1, 2]);
+
+        assert_eq!(set.pop_front().unwrap().key, 3);
+        assert_eq!(set.pop_back().unwrap().key, 2);
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 struct Test {
     key: i32,
     value: i32,
@@ -16,3 +16,138 @@ impl ToPartial<i32> for Test {
 fn test_equality() {
     assert_eq!(*Test { key: 1, value: 2 }.to_partial(), 1i32);
 }
+
+#[test]
+fn test_set_algebra() {
+    let mut a = PartialSet::new();
+    a.insert(Test { key: 1, value: 2 });
+    a.insert(Test { key: 2, value: 3 });
+
+    let mut b = PartialSet::new();
+    b.insert(Test { key: 2, value: 99 });
+    b.insert(Test { key: 3, value: 4 });
+
+    assert_eq!(a.union(&b).count(), 3);
+    assert_eq!(a.intersection(&b).count(), 1);
+    assert_eq!(a.symmetric_difference(&b).count(), 2);
+    assert!(!a.is_disjoint(&b));
+    assert!(!a.is_subset(&b));
+    assert!(!a.is_superset(&b));
+
+    let union = &a | &b;
+    assert_eq!(union.len(), 3);
+    let intersection = &a & &b;
+    assert_eq!(intersection.len(), 1);
+    let xor = &a ^ &b;
+    assert_eq!(xor.len(), 2);
+    let diff = &a - &b;
+    assert_eq!(diff.len(), 1);
+}
+
+#[test]
+fn test_get_or_insert_and_entry() {
+    let mut set = PartialSet::new();
+
+    let value = set.get_or_insert(Test { key: 1, value: 2 });
+    assert_eq!(value.value, 2);
+    let value = set.get_or_insert(Test { key: 1, value: 99 });
+    assert_eq!(value.value, 2);
+
+    match set.entry(2) {
+        Entry::Vacant(entry) => {
+            let value = entry.insert(Test { key: 2, value: 3 });
+            assert_eq!(value.value, 3);
+        }
+        Entry::Occupied(_) => panic!("expected vacant entry"),
+    }
+
+    match set.entry(2) {
+        Entry::Occupied(entry) => assert_eq!(entry.get().value, 3),
+        Entry::Vacant(_) => panic!("expected occupied entry"),
+    }
+}
+
+#[test]
+fn test_extract_if() {
+    let mut set = PartialSet::new();
+    set.insert(Test { key: 1, value: 1 });
+    set.insert(Test { key: 2, value: 2 });
+    set.insert(Test { key: 3, value: 3 });
+
+    let mut extracted: Vec<i32> = set.extract_if(|v| v.value % 2 == 0).map(|v| v.key).collect();
+    extracted.sort();
+
+    assert_eq!(extracted, vec![2]);
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&1));
+    assert!(set.contains(&3));
+}
+
+#[test]
+fn test_extract_if_dropped_early_keeps_untouched_elements() {
+    let mut set = PartialSet::new();
+    set.insert(Test { key: 1, value: 2 });
+    set.insert(Test { key: 2, value: 2 });
+
+    let removed_key = {
+        let mut iter = set.extract_if(|v| v.value % 2 == 0);
+        let removed_key = iter.next().unwrap().key;
+        // drop the iterator without visiting the second matching element
+        removed_key
+    };
+
+    // elements the iterator never visited are left in place: the
+    // still-matching element that was never visited remains in the set.
+    assert_eq!(set.len(), 1);
+    let remaining_key = if removed_key == 1 { 2 } else { 1 };
+    assert!(set.contains(&remaining_key));
+}
+
+#[test]
+fn test_get_mut_and_update_with() {
+    let mut set = PartialSet::new();
+    set.insert(Test { key: 1, value: 1 });
+
+    {
+        let mut value = set.get_mut(&1).unwrap();
+        value.value = 42;
+    }
+    assert_eq!(set.get(&1).unwrap().value, 42);
+
+    assert!(set.update_with(&1, |v| v.value += 1));
+    assert_eq!(set.get(&1).unwrap().value, 43);
+    assert!(!set.update_with(&2, |v| v.value += 1));
+}
+
+#[test]
+#[should_panic(expected = "logic error")]
+fn test_get_mut_panics_on_key_change() {
+    let mut set = PartialSet::new();
+    set.insert(Test { key: 1, value: 1 });
+
+    let mut value = set.get_mut(&1).unwrap();
+    value.key = 2;
+}
+
+#[test]
+fn test_collection_traits() {
+    let values = vec![
+        Test { key: 1, value: 1 },
+        Test { key: 2, value: 2 },
+        Test { key: 1, value: 99 },
+    ];
+
+    let mut set: PartialSet<Test, i32> = values.into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert_eq!(set.get(&1).unwrap().value, 1);
+
+    set.extend(vec![Test { key: 3, value: 3 }]);
+    assert_eq!(set.len(), 3);
+
+    let cloned = set.clone();
+    assert_eq!(cloned.len(), set.len());
+    assert_eq!(cloned.get(&3).unwrap().value, 3);
+
+    let empty: PartialSet<Test, i32> = PartialSet::default();
+    assert!(empty.is_empty());
+}